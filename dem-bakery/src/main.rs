@@ -9,11 +9,13 @@ use speedy::Writable;
 fn main() -> anyhow::Result<()> {
     let path = PathBuf::from("dem-bakery/assets/Mars_MGS_MOLA_DEM_mosaic_global_463m.tif");
 
-    let dem_chunks = Dem::load_chunks_from_image(&path, 1024 * 8, 1024 * 8, MarsMola::DEM_PROFILE)?;
+    let mut dem_chunks = Dem::load_chunks_from_image(&path, 1024 * 8, 1024 * 8, MarsMola::DEM_PROFILE)?;
 
     let result_dir = path.parent().unwrap().join(path.file_stem().unwrap());
     std::fs::create_dir(&result_dir)?;
-    for dem_chunk in dem_chunks {
+    for dem_chunk in &mut dem_chunks {
+        dem_chunk.bake_mip_pyramid();
+
         let bytes = dem_chunk.write_to_vec().unwrap();
 
         let filename = path.file_stem().unwrap().to_str().unwrap();
@@ -0,0 +1,112 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use glam::Vec2;
+use lru::LruCache;
+use speedy::Readable;
+
+use crate::{Dem, DemProfile};
+
+/// Indexes a directory of baked `.dem` chunks (named
+/// `*_<width_offset>_<height_offset>.dem`, as written by [`Dem::write_to_vec`])
+/// and lazily loads only the chunks needed to answer a query, keeping a
+/// bounded LRU cache of decoded chunks in memory. Lets an application pan
+/// over a mosaic far larger than memory without holding every chunk resident.
+pub struct DemAtlas {
+    dir: PathBuf,
+    chunk_width: u32,
+    chunk_height: u32,
+    profile: DemProfile,
+    cache: LruCache<(u32, u32), Dem>,
+}
+
+impl DemAtlas {
+    /// Index `dir` for baked chunks of a mosaic described by `profile`,
+    /// caching up to `cache_capacity` decoded chunks at a time.
+    pub fn new(
+        dir: &Path,
+        chunk_width: u32,
+        chunk_height: u32,
+        profile: DemProfile,
+        cache_capacity: NonZeroUsize,
+    ) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            chunk_width,
+            chunk_height,
+            profile,
+            cache: LruCache::new(cache_capacity),
+        }
+    }
+
+    /// Elevation in meters at a global pixel coordinate, loading its owning
+    /// chunk on demand.
+    pub fn get_elevation(&mut self, global_x: u32, global_y: u32) -> anyhow::Result<f32> {
+        let chunk_x = (global_x / self.chunk_width) * self.chunk_width;
+        let chunk_y = (global_y / self.chunk_height) * self.chunk_height;
+
+        let chunk = self.load_chunk(chunk_x, chunk_y)?;
+        Ok(chunk.get_elevation(global_x - chunk_x, global_y - chunk_y))
+    }
+
+    /// Bilinearly sample elevation in meters at (latitude, longitude), both
+    /// in radians, reading neighboring chunks when the sample straddles a
+    /// chunk boundary.
+    pub fn sample_elevation(&mut self, latitude: f32, longitude: f32) -> anyhow::Result<f32> {
+        let uv = Vec2::new(
+            (longitude - self.profile.lon_min) / (self.profile.lon_max - self.profile.lon_min),
+            (self.profile.lat_max - latitude) / (self.profile.lat_max - self.profile.lat_min),
+        );
+
+        let max_x = self.profile.width - 1;
+        let max_y = self.profile.height - 1;
+
+        let fx = uv.x * max_x as f32;
+        let fy = uv.y * max_y as f32;
+
+        let x0 = fx.floor() as u32;
+        let y0 = fy.floor() as u32;
+        let x1 = (x0 + 1).min(max_x);
+        let y1 = (y0 + 1).min(max_y);
+
+        let tx = fx - x0 as f32;
+        let ty = fy - y0 as f32;
+
+        let v00 = self.get_elevation(x0, y0)?;
+        let v10 = self.get_elevation(x1, y0)?;
+        let v01 = self.get_elevation(x0, y1)?;
+        let v11 = self.get_elevation(x1, y1)?;
+
+        let top = v00 * (1.0 - tx) + v10 * tx;
+        let bottom = v01 * (1.0 - tx) + v11 * tx;
+        Ok(top * (1.0 - ty) + bottom * ty)
+    }
+
+    fn load_chunk(&mut self, chunk_x: u32, chunk_y: u32) -> anyhow::Result<&Dem> {
+        if !self.cache.contains(&(chunk_x, chunk_y)) {
+            let path = self.chunk_path(chunk_x, chunk_y)?;
+            let bytes = fs::read(path)?;
+            let dem = Dem::read_from_buffer(&bytes)?;
+            self.cache.put((chunk_x, chunk_y), dem);
+        }
+
+        Ok(self.cache.get(&(chunk_x, chunk_y)).expect("just inserted"))
+    }
+
+    fn chunk_path(&self, chunk_x: u32, chunk_y: u32) -> anyhow::Result<PathBuf> {
+        let suffix = format!("_{chunk_x}_{chunk_y}.dem");
+
+        fs::read_dir(&self.dir)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .find(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.ends_with(&suffix))
+            })
+            .ok_or_else(|| anyhow::anyhow!("no chunk baked for offset ({chunk_x}, {chunk_y})"))
+    }
+}
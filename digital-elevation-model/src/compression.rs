@@ -0,0 +1,291 @@
+use std::{fs, ops::Range, path::Path};
+
+use speedy::{Readable, Writable};
+
+use crate::{Dem, DemProfile};
+
+/// On-disk compressed representation of a [`Dem`], written by
+/// [`Dem::write_compressed`] and read back by [`Dem::read_compressed`].
+#[derive(Debug, Clone, Readable, Writable)]
+struct CompressedDem {
+    width_range: Range<u32>,
+    height_range: Range<u32>,
+    profile: DemProfile,
+    residuals: Vec<u8>,
+    /// Mip levels baked by [`Dem::bake_mip_pyramid`], compressed the same
+    /// way as the full-resolution level. Empty if the pyramid wasn't baked.
+    mips: Vec<CompressedMip>,
+}
+
+/// Compressed representation of a single [`Dem::build_mip_pyramid`] level.
+#[derive(Debug, Clone, Readable, Writable)]
+struct CompressedMip {
+    width_range: Range<u32>,
+    height_range: Range<u32>,
+    residuals: Vec<u8>,
+}
+
+impl Dem {
+    /// Losslessly compress and write this dem to `path` using the LOCO-I/MED
+    /// predictor from lossless JPEG-LS followed by context-adaptive Rice
+    /// coding of the residuals. Terrain is smooth, so residuals cluster
+    /// around zero and the result is much smaller than the raw [`speedy`]
+    /// encoding while remaining bit-exact on the underlying `u16` samples.
+    /// Any mip pyramid baked via [`Dem::bake_mip_pyramid`] is compressed and
+    /// persisted alongside the full-resolution level.
+    pub fn write_compressed(&self, path: &Path) -> anyhow::Result<()> {
+        let residuals = encode_residuals(&self.dem, self.width());
+
+        let mips = self
+            .mips
+            .iter()
+            .map(|mip| CompressedMip {
+                width_range: mip.width_range.clone(),
+                height_range: mip.height_range.clone(),
+                residuals: encode_residuals(&mip.dem, mip.width()),
+            })
+            .collect();
+
+        let compressed = CompressedDem {
+            width_range: self.width_range.clone(),
+            height_range: self.height_range.clone(),
+            profile: self.profile.clone(),
+            residuals,
+            mips,
+        };
+
+        fs::write(path, compressed.write_to_vec()?)?;
+        Ok(())
+    }
+
+    /// Read back a dem previously written by [`Dem::write_compressed`],
+    /// including any persisted mip pyramid.
+    pub fn read_compressed(path: &Path) -> anyhow::Result<Self> {
+        let bytes = fs::read(path)?;
+        let compressed = CompressedDem::read_from_buffer(&bytes)?;
+
+        let width = compressed.width_range.end - compressed.width_range.start;
+        let height = compressed.height_range.end - compressed.height_range.start;
+        let dem = decode_residuals(&compressed.residuals, width, width * height);
+
+        let mips = compressed
+            .mips
+            .into_iter()
+            .map(|mip| {
+                let mip_width = mip.width_range.end - mip.width_range.start;
+                let mip_height = mip.height_range.end - mip.height_range.start;
+
+                Dem {
+                    width_range: mip.width_range,
+                    height_range: mip.height_range,
+                    profile: compressed.profile.clone(),
+                    dem: decode_residuals(&mip.residuals, mip_width, mip_width * mip_height),
+                    mips: Vec::new(),
+                }
+            })
+            .collect();
+
+        Ok(Dem {
+            width_range: compressed.width_range,
+            height_range: compressed.height_range,
+            profile: compressed.profile,
+            dem,
+            mips,
+        })
+    }
+}
+
+/// MED (median edge detector) predictor from LOCO-I/JPEG-LS: predict from
+/// the left neighbor `a`, the above neighbor `b` and the above-left `c`.
+fn predict(a: i32, b: i32, c: i32) -> i32 {
+    if c >= a.max(b) {
+        a.min(b)
+    } else if c <= a.min(b) {
+        a.max(b)
+    } else {
+        a + b - c
+    }
+}
+
+/// Fold a signed residual into an unsigned value, smallest magnitudes first.
+fn zigzag_encode(v: i32) -> u32 {
+    ((v << 1) ^ (v >> 31)) as u32
+}
+
+fn zigzag_decode(v: u32) -> i32 {
+    ((v >> 1) as i32) ^ -((v & 1) as i32)
+}
+
+/// Nudge the Rice parameter towards the magnitude of the residual stream.
+fn adapt_k(k: u32, mapped: u32) -> u32 {
+    if mapped >= (1 << k) {
+        (k + 1).min(16)
+    } else if k > 0 && mapped < (1 << (k - 1)) {
+        k - 1
+    } else {
+        k
+    }
+}
+
+fn encode_residuals(samples: &[u16], width: u32) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut k: u32 = 0;
+
+    for (i, &sample) in samples.iter().enumerate() {
+        let i = i as u32;
+        let x = i % width;
+        let y = i / width;
+
+        let a = if x > 0 { samples[(i - 1) as usize] as i32 } else { 0 };
+        let b = if y > 0 {
+            samples[(i - width) as usize] as i32
+        } else {
+            0
+        };
+        let c = if x > 0 && y > 0 {
+            samples[(i - width - 1) as usize] as i32
+        } else {
+            0
+        };
+
+        let residual = sample as i32 - predict(a, b, c);
+        let mapped = zigzag_encode(residual);
+
+        writer.write_rice(mapped, k);
+        k = adapt_k(k, mapped);
+    }
+
+    writer.finish()
+}
+
+fn decode_residuals(bytes: &[u8], width: u32, count: u32) -> Vec<u16> {
+    let mut reader = BitReader::new(bytes);
+    let mut samples = Vec::with_capacity(count as usize);
+    let mut k: u32 = 0;
+
+    for i in 0..count {
+        let x = i % width;
+        let y = i / width;
+
+        let a = if x > 0 { samples[(i - 1) as usize] as i32 } else { 0 };
+        let b = if y > 0 {
+            samples[(i - width) as usize] as i32
+        } else {
+            0
+        };
+        let c = if x > 0 && y > 0 {
+            samples[(i - width - 1) as usize] as i32
+        } else {
+            0
+        };
+
+        let mapped = reader.read_rice(k);
+        let residual = zigzag_decode(mapped);
+        let sample = (predict(a, b, c) + residual) as u16;
+
+        samples.push(sample);
+        k = adapt_k(k, mapped);
+    }
+
+    samples
+}
+
+/// MSB-first bit writer backing the Rice-coded residual stream.
+struct BitWriter {
+    bytes: Vec<u8>,
+    cur: u8,
+    nbits: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            cur: 0,
+            nbits: 0,
+        }
+    }
+
+    fn write_bit(&mut self, bit: bool) {
+        self.cur = (self.cur << 1) | (bit as u8);
+        self.nbits += 1;
+        if self.nbits == 8 {
+            self.bytes.push(self.cur);
+            self.cur = 0;
+            self.nbits = 0;
+        }
+    }
+
+    fn write_bits(&mut self, value: u32, n: u32) {
+        for i in (0..n).rev() {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+
+    /// Unary-code the quotient `value >> k`, then emit the `k`-bit remainder.
+    fn write_rice(&mut self, value: u32, k: u32) {
+        let quotient = value >> k;
+        for _ in 0..quotient {
+            self.write_bit(true);
+        }
+        self.write_bit(false);
+
+        if k > 0 {
+            self.write_bits(value & ((1 << k) - 1), k);
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.nbits > 0 {
+            self.cur <<= 8 - self.nbits;
+            self.bytes.push(self.cur);
+        }
+        self.bytes
+    }
+}
+
+/// MSB-first bit reader, mirroring [`BitWriter`].
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    byte_idx: usize,
+    bit_idx: u8,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            byte_idx: 0,
+            bit_idx: 0,
+        }
+    }
+
+    fn read_bit(&mut self) -> bool {
+        let byte = self.bytes[self.byte_idx];
+        let bit = (byte >> (7 - self.bit_idx)) & 1 == 1;
+        self.bit_idx += 1;
+        if self.bit_idx == 8 {
+            self.bit_idx = 0;
+            self.byte_idx += 1;
+        }
+        bit
+    }
+
+    fn read_bits(&mut self, n: u32) -> u32 {
+        let mut value = 0;
+        for _ in 0..n {
+            value = (value << 1) | (self.read_bit() as u32);
+        }
+        value
+    }
+
+    fn read_rice(&mut self, k: u32) -> u32 {
+        let mut quotient = 0;
+        while self.read_bit() {
+            quotient += 1;
+        }
+
+        let remainder = if k > 0 { self.read_bits(k) } else { 0 };
+        (quotient << k) | remainder
+    }
+}
@@ -1,5 +1,11 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
 use crate::DemProfile;
 
+/// Radius in meters of Mars, used as `DemProfile::planet_radius` by the Mars
+/// database entries below.
+pub const MARS_RADIUS_METERS: f32 = 3_396_190.0;
+
 pub trait DatabaseEntry {
     /// Original data source
     const ORIGINAL_DATA_SRC: &'static str;
@@ -19,10 +25,17 @@ impl DatabaseEntry for MarsHrscMolaBlend {
         "https://drive.google.com/file/d/1G_x3rypkYM_UoqroRskB8oMpKIKr55S3/view?usp=sharing";
 
     const DEM_PROFILE: DemProfile = DemProfile {
-        width: todo!(),
-        height: todo!(),
+        width: 106694,
+        height: 53347,
         meters_per_pixel: 200.0,
+        min_elevation: 0.0,
         max_elevation: 1.0,
+        no_data_elevation: None,
+        planet_radius: MARS_RADIUS_METERS,
+        lon_min: -PI,
+        lon_max: PI,
+        lat_min: -FRAC_PI_2,
+        lat_max: FRAC_PI_2,
     };
 
     const CHUNK_SIZE: u32 = 1024 * 8;
@@ -39,7 +52,14 @@ impl DatabaseEntry for MarsMola {
         width: 46080,
         height: 23040,
         meters_per_pixel: 463.0,
+        min_elevation: 0.0,
         max_elevation: 1.0,
+        no_data_elevation: None,
+        planet_radius: MARS_RADIUS_METERS,
+        lon_min: -PI,
+        lon_max: PI,
+        lat_min: -FRAC_PI_2,
+        lat_max: FRAC_PI_2,
     };
 
     const CHUNK_SIZE: u32 = 1024 * 8;
@@ -5,9 +5,18 @@ use half::f16;
 use image::ImageReader;
 use speedy::{Readable, Writable};
 
+pub mod atlas;
+pub mod compression;
 pub mod database;
+pub mod mip;
+pub mod raster;
+pub mod raycast;
+pub mod shading;
+pub mod spherical;
 
+pub use atlas::DemAtlas;
 pub use database::*;
+pub use raycast::RayHit;
 
 /// Digital elevation model profile, describes auxilery data
 #[derive(Debug, Clone, Readable, Writable)]
@@ -18,8 +27,26 @@ pub struct DemProfile {
     pub height: u32,
     /// Number of meters a single pixel represents inside the dem
     pub meters_per_pixel: f32,
+    /// Minimum elevation in meters of the total dem
+    pub min_elevation: f32,
     /// Maximum elevation in meters of the total dem
     pub max_elevation: f32,
+    /// Elevation in meters that marks a no-data/void sample, if the source
+    /// raster declared one. `get_elevation` returns this value, rounded to
+    /// the dem's f16 storage precision, for void pixels rather than silently
+    /// reading them back as valid terrain. `sample_elevation` blends it
+    /// bilinearly with neighboring samples like any other value.
+    pub no_data_elevation: Option<f32>,
+    /// Radius in meters of the body this dem was sampled from
+    pub planet_radius: f32,
+    /// Minimum longitude covered by the dem, in radians
+    pub lon_min: f32,
+    /// Maximum longitude covered by the dem, in radians
+    pub lon_max: f32,
+    /// Minimum latitude covered by the dem, in radians
+    pub lat_min: f32,
+    /// Maximum latitude covered by the dem, in radians
+    pub lat_max: f32,
 }
 
 impl Default for DemProfile {
@@ -28,7 +55,14 @@ impl Default for DemProfile {
             width: 1,
             height: 1,
             meters_per_pixel: 1.0,
+            min_elevation: 0.0,
             max_elevation: 1.0,
+            no_data_elevation: None,
+            planet_radius: 1.0,
+            lon_min: -std::f32::consts::PI,
+            lon_max: std::f32::consts::PI,
+            lat_min: -std::f32::consts::FRAC_PI_2,
+            lat_max: std::f32::consts::FRAC_PI_2,
         }
     }
 }
@@ -40,6 +74,9 @@ pub struct Dem {
     height_range: Range<u32>,
     profile: DemProfile,
     dem: Vec<u16>,
+    /// Successively half-resolution levels, baked by [`Dem::bake_mip_pyramid`].
+    /// Empty until baked.
+    mips: Vec<Dem>,
 }
 
 impl Dem {
@@ -79,6 +116,7 @@ impl Dem {
                     height_range,
                     profile: profile.clone(),
                     dem,
+                    mips: Vec::new(),
                 })
             }
         }
@@ -134,14 +172,17 @@ impl Dem {
     pub fn get_elevation(&self, x: u32, y: u32) -> f32 {
         let elevation_u16 = self.dem[(y * self.width() + x) as usize];
         let elevation_f16 = f16::from_bits(elevation_u16);
-        elevation_f16.to_f32() * self.profile.max_elevation
+        self.denormalize_elevation(elevation_f16.to_f32())
     }
 
-    /// Sample elevation bilinearly at (latitude, longitude)
+    /// Sample elevation bilinearly at (latitude, longitude), both in radians,
+    /// mapped into this dem's actual geographic bounds rather than assuming
+    /// a full equirectangular globe.
     pub fn sample_elevation(&self, latitude: f32, longitude: f32) -> f32 {
-        let lon_degrees = longitude.to_degrees();
-        let lat_degrees = latitude.to_degrees();
-        let uv = Vec2::new((lon_degrees + 180.0) / 360.0, (90.0 - lat_degrees) / 180.0);
+        let uv = Vec2::new(
+            (longitude - self.profile.lon_min) / (self.profile.lon_max - self.profile.lon_min),
+            (self.profile.lat_max - latitude) / (self.profile.lat_max - self.profile.lat_min),
+        );
 
         self.sample_elevation_uv(uv)
     }
@@ -152,9 +193,11 @@ impl Dem {
         let fx = uv.x * (self.width() - 1) as f32;
         let fy = uv.y * (self.height() - 1) as f32;
 
-        // Integer parts
-        let x0 = fx.floor() as u32;
-        let y0 = fy.floor() as u32;
+        // Integer parts, clamped so an out-of-[0, 1] uv (e.g. a grazing
+        // raycast sample landing exactly on the far edge) can't index past
+        // the last row/column.
+        let x0 = (fx.floor() as u32).min(self.width() - 1);
+        let y0 = (fy.floor() as u32).min(self.height() - 1);
 
         // Ensure we don't read out of bounds by clamping to valid indices
         let x1 = (x0 + 1).min(self.width() - 1);
@@ -181,6 +224,13 @@ impl Dem {
         let bottom = v01 * (1.0 - tx) + v11 * tx;
         let bilinear = top * (1.0 - ty) + bottom * ty;
 
-        bilinear * self.profile.max_elevation
+        self.denormalize_elevation(bilinear)
+    }
+
+    /// Map a `[0, 1]`-normalized sample back to meters using the profile's
+    /// elevation bounds.
+    fn denormalize_elevation(&self, normalized: f32) -> f32 {
+        self.profile.min_elevation
+            + normalized * (self.profile.max_elevation - self.profile.min_elevation)
     }
 }
@@ -0,0 +1,74 @@
+use glam::Vec2;
+use half::f16;
+
+use crate::Dem;
+
+impl Dem {
+    /// Produce successively half-resolution mip levels by 2x2 box-averaging
+    /// the normalized elevation samples, clamping at the last row/column
+    /// when a dimension is odd. Stops once a level reaches 1x1.
+    pub fn build_mip_pyramid(&self) -> Vec<Dem> {
+        let mut levels = Vec::new();
+        let mut current = self.downsample();
+
+        while current.width() > 1 || current.height() > 1 {
+            let next = current.downsample();
+            levels.push(current);
+            current = next;
+        }
+        levels.push(current);
+
+        levels
+    }
+
+    /// Build the mip pyramid and persist it on this dem so it serializes
+    /// alongside the full-resolution data, making it available via
+    /// [`Dem::sample_elevation_uv_lod`] without rescanning the base level.
+    pub fn bake_mip_pyramid(&mut self) {
+        self.mips = self.build_mip_pyramid();
+    }
+
+    /// Sample elevation bilinearly at `uv` from mip level `lod`, where `0` is
+    /// the full-resolution level. Levels beyond a baked pyramid's depth clamp
+    /// to the coarsest one. Falls back to the full-resolution level if no
+    /// pyramid has been baked.
+    pub fn sample_elevation_uv_lod(&self, uv: Vec2, lod: usize) -> f32 {
+        if lod == 0 || self.mips.is_empty() {
+            return self.sample_elevation_uv(uv);
+        }
+
+        let level = &self.mips[(lod - 1).min(self.mips.len() - 1)];
+        level.sample_elevation_uv(uv)
+    }
+
+    fn downsample(&self) -> Dem {
+        let width = (self.width().div_ceil(2)).max(1);
+        let height = (self.height().div_ceil(2)).max(1);
+
+        let sample = |x: u32, y: u32| -> f32 {
+            f16::from_bits(self.dem[(y * self.width() + x) as usize]).to_f32()
+        };
+
+        let mut dem = vec![0u16; (width * height) as usize];
+        for y in 0..height {
+            for x in 0..width {
+                let x0 = (x * 2).min(self.width() - 1);
+                let x1 = (x * 2 + 1).min(self.width() - 1);
+                let y0 = (y * 2).min(self.height() - 1);
+                let y1 = (y * 2 + 1).min(self.height() - 1);
+
+                let average = (sample(x0, y0) + sample(x1, y0) + sample(x0, y1) + sample(x1, y1))
+                    / 4.0;
+                dem[(y * width + x) as usize] = f16::from_f32(average).to_bits();
+            }
+        }
+
+        Dem {
+            width_range: 0..width,
+            height_range: 0..height,
+            profile: self.profile.clone(),
+            dem,
+            mips: Vec::new(),
+        }
+    }
+}
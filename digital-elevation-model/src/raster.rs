@@ -0,0 +1,145 @@
+use std::path::Path;
+
+use gdal::Dataset;
+use half::f16;
+
+use crate::{Dem, DemProfile};
+
+impl Dem {
+    /// Load dem chunks from a single-band raster (e.g. a MOLA GeoTIFF holding
+    /// signed int16 meters), reading the true elevation values via GDAL
+    /// instead of deriving them from an RGB preview image. `width`, `height`
+    /// and `meters_per_pixel` are taken from the raster's geotransform rather
+    /// than a [`crate::database::DatabaseEntry`], and the band's min/max
+    /// populate [`DemProfile::min_elevation`]/[`DemProfile::max_elevation`]
+    /// so `get_elevation` returns correct meters for arbitrary DEMs. The
+    /// band's no-data value, if any, is exposed via
+    /// [`DemProfile::no_data_elevation`] so void samples can be told apart
+    /// from real terrain. [`DemProfile::planet_radius`] is read from the
+    /// raster's spatial reference and the lon/lat bounds are derived from
+    /// the geotransform, so `sample_elevation`/`world_size_meters`/
+    /// `lonlat_to_cartesian` work for partial-extent rasters too. Rasters
+    /// without a spatial reference carrying a semi-major axis are rejected
+    /// rather than silently falling back to a meaningless unit radius.
+    pub fn load_chunks_from_raster(
+        path: &Path,
+        chunk_width: u32,
+        chunk_height: u32,
+    ) -> anyhow::Result<Vec<Self>> {
+        let dataset = Dataset::open(path)?;
+        let band = dataset.rasterband(1)?;
+
+        let (raster_width, raster_height) = dataset.raster_size();
+        let width = raster_width as u32;
+        let height = raster_height as u32;
+
+        let geo_transform = dataset.geo_transform()?;
+        let meters_per_pixel = geo_transform[1].abs() as f32;
+
+        // The geotransform's origin/extent are in the same projected meters
+        // as `meters_per_pixel` (simple cylindrical/plate carrée, as used by
+        // the planetary mosaics this crate targets), so dividing by the
+        // body's radius recovers longitude/latitude in radians. A missing or
+        // unreadable semi-major axis means we can't recover real lon/lat
+        // bounds, so bail out instead of quietly defaulting to a unit
+        // radius that would make every downstream spherical sample wrong.
+        let planet_radius = dataset.spatial_ref()?.semi_major()? as f32;
+
+        let lon_min = geo_transform[0] as f32 / planet_radius;
+        let lon_max = (geo_transform[0] + geo_transform[1] * raster_width as f64) as f32 / planet_radius;
+        let lat_max = geo_transform[3] as f32 / planet_radius;
+        let lat_min = (geo_transform[3] + geo_transform[5] * raster_height as f64) as f32 / planet_radius;
+
+        let no_data_value = band.no_data_value();
+        let buffer = band.read_as::<f32>(
+            (0, 0),
+            (raster_width, raster_height),
+            (raster_width, raster_height),
+            None,
+        )?;
+        let samples = buffer.data();
+
+        let mut min_elevation = f32::INFINITY;
+        let mut max_elevation = f32::NEG_INFINITY;
+        for &value in samples {
+            if no_data_value == Some(value as f64) {
+                continue;
+            }
+            min_elevation = min_elevation.min(value);
+            max_elevation = max_elevation.max(value);
+        }
+
+        let no_data_elevation = no_data_value.map(|value| value as f32);
+
+        let profile = DemProfile {
+            width,
+            height,
+            meters_per_pixel,
+            min_elevation,
+            max_elevation,
+            no_data_elevation,
+            planet_radius,
+            lon_min,
+            lon_max,
+            lat_min,
+            lat_max,
+        };
+
+        let num_chunks_x = width.div_ceil(chunk_width);
+        let num_chunks_y = height.div_ceil(chunk_height);
+
+        let mut chunks = Vec::new();
+        for cy in 0..num_chunks_y {
+            for cx in 0..num_chunks_x {
+                let width_range = (cx * chunk_width)..((cx + 1) * chunk_width).min(width);
+                let height_range = (cy * chunk_height)..((cy + 1) * chunk_height).min(height);
+                let dem = vec![
+                    0;
+                    ((width_range.end - width_range.start)
+                        * (height_range.end - height_range.start))
+                        as usize
+                ];
+
+                chunks.push(Dem {
+                    width_range,
+                    height_range,
+                    profile: profile.clone(),
+                    dem,
+                    mips: Vec::new(),
+                });
+            }
+        }
+
+        let elevation_span = (max_elevation - min_elevation).max(f32::EPSILON);
+        // Void samples read back through get_elevation as `no_data_elevation`
+        // (to f16 precision) rather than normalizing to 0.0, which would read back as valid
+        // lowest terrain indistinguishable from real data.
+        let no_data_normalized =
+            no_data_elevation.map(|sentinel| (sentinel - min_elevation) / elevation_span);
+
+        for y in 0..height {
+            for x in 0..width {
+                let value = samples[(y * width + x) as usize];
+                let normalized = if no_data_value == Some(value as f64) {
+                    no_data_normalized.expect("no_data_elevation set whenever no_data_value is")
+                } else {
+                    (value - min_elevation) / elevation_span
+                };
+                let elevation_u16 = f16::from_f32(normalized).to_bits();
+
+                let chunk_idx_x = x / chunk_width;
+                let chunk_idx_y = y / chunk_height;
+                let chunk_idx = chunk_idx_y * num_chunks_x + chunk_idx_x;
+                let chunk = &mut chunks[chunk_idx as usize];
+
+                let dem_idx_x = x % chunk_width;
+                let dem_idx_y = y % chunk_height;
+                let dem_idx = dem_idx_y * chunk.width() + dem_idx_x;
+
+                chunk.dem[dem_idx as usize] = elevation_u16;
+            }
+        }
+
+        Ok(chunks)
+    }
+}
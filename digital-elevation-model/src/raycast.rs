@@ -0,0 +1,185 @@
+use glam::{Vec2, Vec3};
+
+use crate::Dem;
+
+/// Result of a successful [`Dem::raycast`] query
+#[derive(Debug, Clone, Copy)]
+pub struct RayHit {
+    /// Hit position in pixel space, (x, y)
+    pub pixel: Vec2,
+    /// Hit position as (latitude, longitude) in radians
+    pub lat_lon: Vec2,
+    /// Distance along the ray, in the same units as the ray's `dir`
+    pub distance: f32,
+    /// Interpolated terrain elevation in meters at the hit
+    pub elevation: f32,
+}
+
+impl Dem {
+    /// Cast a ray against the elevation surface, treating `origin`/`dir` as
+    /// (pixel_x, pixel_y, elevation_meters). Marches the ray cell-by-cell
+    /// using a 2D DDA over the pixel grid and binary searches the first cell
+    /// whose entry/exit heights bracket the bilinearly interpolated terrain.
+    ///
+    /// Returns `None` if the ray never crosses the surface before leaving
+    /// the dem's pixel bounds. Also returns `None` when `origin` lies outside
+    /// those bounds; such rays are not clipped into the dem.
+    pub fn raycast(&self, origin: Vec3, dir: Vec3) -> Option<RayHit> {
+        let max_x = (self.width() - 1) as f32;
+        let max_y = (self.height() - 1) as f32;
+
+        if origin.x < 0.0 || origin.x > max_x || origin.y < 0.0 || origin.y > max_y {
+            return None;
+        }
+
+        // Already under the surface: immediate hit at the origin.
+        let origin_surface = self.height_at_pixel(origin.x, origin.y);
+        if origin.z <= origin_surface {
+            return Some(self.ray_hit_at(origin, dir, 0.0));
+        }
+
+        let mut ix = origin.x.floor() as i64;
+        let mut iy = origin.y.floor() as i64;
+
+        let step_x: i64 = if dir.x > 0.0 {
+            1
+        } else if dir.x < 0.0 {
+            -1
+        } else {
+            0
+        };
+        let step_y: i64 = if dir.y > 0.0 {
+            1
+        } else if dir.y < 0.0 {
+            -1
+        } else {
+            0
+        };
+
+        let t_delta_x = if dir.x != 0.0 {
+            (1.0 / dir.x).abs()
+        } else {
+            f32::INFINITY
+        };
+        let t_delta_y = if dir.y != 0.0 {
+            (1.0 / dir.y).abs()
+        } else {
+            f32::INFINITY
+        };
+
+        let next_x_boundary = if step_x > 0 {
+            (ix + 1) as f32
+        } else {
+            ix as f32
+        };
+        let next_y_boundary = if step_y > 0 {
+            (iy + 1) as f32
+        } else {
+            iy as f32
+        };
+
+        let mut t_max_x = if dir.x != 0.0 {
+            (next_x_boundary - origin.x) / dir.x
+        } else {
+            f32::INFINITY
+        };
+        let mut t_max_y = if dir.y != 0.0 {
+            (next_y_boundary - origin.y) / dir.y
+        } else {
+            f32::INFINITY
+        };
+
+        let mut t_enter = 0.0f32;
+
+        while ix >= 0 && ix <= max_x as i64 && iy >= 0 && iy <= max_y as i64 {
+            let t_exit = t_max_x.min(t_max_y);
+
+            let enter_pos = origin + dir * t_enter;
+            let exit_pos = origin + dir * t_exit;
+
+            let enter_surface = self.height_at_pixel(enter_pos.x, enter_pos.y);
+            let exit_surface = self.height_at_pixel(exit_pos.x, exit_pos.y);
+
+            let enter_diff = enter_pos.z - enter_surface;
+            let exit_diff = exit_pos.z - exit_surface;
+
+            if enter_diff <= 0.0 || exit_diff <= 0.0 || enter_diff.signum() != exit_diff.signum() {
+                if let Some(t_hit) = self.bisect_crossing(origin, dir, t_enter, t_exit) {
+                    return Some(self.ray_hit_at(origin, dir, t_hit));
+                }
+            }
+
+            if t_max_x < t_max_y {
+                ix += step_x;
+                t_enter = t_max_x;
+                t_max_x += t_delta_x;
+            } else {
+                iy += step_y;
+                t_enter = t_max_y;
+                t_max_y += t_delta_y;
+            }
+        }
+
+        None
+    }
+
+    /// Binary search the ray/terrain crossing inside `[t_enter, t_exit]`.
+    fn bisect_crossing(&self, origin: Vec3, dir: Vec3, t_enter: f32, t_exit: f32) -> Option<f32> {
+        let diff_at = |t: f32| -> f32 {
+            let p = origin + dir * t;
+            p.z - self.height_at_pixel(p.x, p.y)
+        };
+
+        let mut lo = t_enter;
+        let mut hi = t_exit;
+        let mut diff_lo = diff_at(lo);
+
+        if diff_lo > 0.0 && diff_at(hi) > 0.0 {
+            return None;
+        }
+
+        for _ in 0..32 {
+            let mid = (lo + hi) * 0.5;
+            let diff_mid = diff_at(mid);
+
+            if diff_lo.signum() == diff_mid.signum() {
+                lo = mid;
+                diff_lo = diff_mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Some(hi)
+    }
+
+    fn ray_hit_at(&self, origin: Vec3, dir: Vec3, t: f32) -> RayHit {
+        let pos = origin + dir * t;
+        let uv = Vec2::new(pos.x / (self.width() - 1) as f32, pos.y / (self.height() - 1) as f32);
+        let lat_lon = Vec2::new(
+            self.profile.lat_max - uv.y * (self.profile.lat_max - self.profile.lat_min),
+            self.profile.lon_min + uv.x * (self.profile.lon_max - self.profile.lon_min),
+        );
+
+        RayHit {
+            pixel: Vec2::new(pos.x, pos.y),
+            lat_lon,
+            distance: t * dir.length(),
+            elevation: self.height_at_pixel(pos.x, pos.y),
+        }
+    }
+
+    /// Bilinearly interpolated elevation in meters at fractional pixel coordinates.
+    ///
+    /// `x`/`y` are clamped into `[0, width-1] x [0, height-1]` first: the DDA
+    /// march in [`Dem::raycast`] samples the exit point of the last cell in
+    /// a row/column, which lands exactly at `x == width` (or `y == height`),
+    /// one past the last valid sample.
+    fn height_at_pixel(&self, x: f32, y: f32) -> f32 {
+        let max_x = (self.width() - 1) as f32;
+        let max_y = (self.height() - 1) as f32;
+
+        let uv = Vec2::new(x.clamp(0.0, max_x) / max_x, y.clamp(0.0, max_y) / max_y);
+        self.sample_elevation_uv(uv)
+    }
+}
@@ -0,0 +1,55 @@
+use glam::Vec3;
+use image::GrayImage;
+
+use crate::Dem;
+
+impl Dem {
+    /// Surface normal at pixel (x, y), computed from the horizontal gradient
+    /// of neighboring elevation samples via central differences. Neighbor
+    /// reads are clamped at chunk borders, matching [`Dem::sample_elevation_uv`].
+    pub fn surface_normal(&self, x: u32, y: u32) -> Vec3 {
+        let meters_per_pixel = self.profile.meters_per_pixel;
+
+        let x0 = x.saturating_sub(1);
+        let x1 = (x + 1).min(self.width() - 1);
+        let y0 = y.saturating_sub(1);
+        let y1 = (y + 1).min(self.height() - 1);
+
+        let dz_dx = (self.get_elevation(x1, y) - self.get_elevation(x0, y))
+            / (2.0 * meters_per_pixel);
+        let dz_dy = (self.get_elevation(x, y1) - self.get_elevation(x, y0))
+            / (2.0 * meters_per_pixel);
+
+        Vec3::new(-dz_dx, -dz_dy, 1.0).normalize()
+    }
+
+    /// Hillshade value at pixel (x, y) for a sun at `sun_azimuth_rad`
+    /// (clockwise from north) and `sun_altitude_rad` above the horizon,
+    /// clamped to `[0, 1]`.
+    pub fn hillshade(&self, x: u32, y: u32, sun_azimuth_rad: f32, sun_altitude_rad: f32) -> f32 {
+        let normal = self.surface_normal(x, y);
+
+        // Pixel rows grow southwards, so north is -y in the normal's frame.
+        let sun_dir = Vec3::new(
+            sun_azimuth_rad.sin() * sun_altitude_rad.cos(),
+            -sun_azimuth_rad.cos() * sun_altitude_rad.cos(),
+            sun_altitude_rad.sin(),
+        );
+
+        normal.dot(sun_dir).max(0.0)
+    }
+
+    /// Bake a hillshade image for the full chunk.
+    pub fn hillshade_image(&self, sun_azimuth_rad: f32, sun_altitude_rad: f32) -> GrayImage {
+        let mut image = GrayImage::new(self.width(), self.height());
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let shade = self.hillshade(x, y, sun_azimuth_rad, sun_altitude_rad);
+                image.put_pixel(x, y, image::Luma([(shade * 255.0) as u8]));
+            }
+        }
+
+        image
+    }
+}
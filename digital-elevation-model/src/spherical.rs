@@ -0,0 +1,30 @@
+use glam::{Vec2, Vec3};
+
+use crate::Dem;
+
+impl Dem {
+    /// Physical size in meters of the geographic bounds this dem covers,
+    /// derived from [`crate::DemProfile::planet_radius`] and its lon/lat
+    /// extent rather than assuming a full global mosaic.
+    pub fn world_size_meters(&self) -> Vec2 {
+        let profile = &self.profile;
+
+        let width_meters = (profile.lon_max - profile.lon_min) * profile.planet_radius;
+        let height_meters = (profile.lat_max - profile.lat_min) * profile.planet_radius;
+
+        Vec2::new(width_meters, height_meters)
+    }
+
+    /// Place a (latitude, longitude) sample, both in radians, on the body's
+    /// sphere at `planet_radius + elevation`.
+    pub fn lonlat_to_cartesian(&self, latitude: f32, longitude: f32) -> Vec3 {
+        let elevation = self.sample_elevation(latitude, longitude);
+        let radius = self.profile.planet_radius + elevation;
+
+        Vec3::new(
+            radius * latitude.cos() * longitude.cos(),
+            radius * latitude.sin(),
+            radius * latitude.cos() * longitude.sin(),
+        )
+    }
+}